@@ -1,7 +1,15 @@
-use reqwest::header::{HeaderValue, CONTENT_TYPE};
+use reqwest::header::{HeaderValue, CONTENT_TYPE, USER_AGENT};
 use serde::{Deserialize, Serialize};
 use url::Url;
 
+/// Default `User-Agent` header value sent with every request
+///
+/// Identifies this crate and its version, e.g. `imgflip/0.1.0`. Override it via
+/// [`ClientBuilder::user_agent`](crate::ClientBuilder::user_agent).
+pub const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+const DEFAULT_BASE_URL: &str = "https://api.imgflip.com";
+
 /// Blank meme template that can be captioned with text boxes
 #[derive(Debug, Deserialize)]
 pub struct MemeTemplate {
@@ -10,7 +18,31 @@ pub struct MemeTemplate {
     url: Url,
     width: u32,
     height: u32,
+    /// Some premium `/search_memes` results encode this as a numeric string rather than a
+    /// number, and omit it entirely for templates still being indexed.
+    #[serde(default, deserialize_with = "deserialize_box_count")]
     box_count: u32,
+    /// Only present on [`Client::search_memes`] results
+    #[serde(default)]
+    captions: Vec<String>,
+}
+
+/// Deserializes [`MemeTemplate::box_count`] from either a JSON number or a numeric string.
+fn deserialize_box_count<'de, D>(deserializer: D) -> std::result::Result<u32, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum BoxCount {
+        Number(u32),
+        String(String),
+    }
+
+    match BoxCount::deserialize(deserializer)? {
+        BoxCount::Number(count) => Ok(count),
+        BoxCount::String(count) => count.parse().map_err(serde::de::Error::custom),
+    }
 }
 
 impl MemeTemplate {
@@ -52,6 +84,23 @@ impl MemeTemplate {
     pub fn box_count(&self) -> u32 {
         self.box_count
     }
+
+    /// Returns example captions for this meme template.
+    ///
+    /// This is only populated by [`Client::search_memes`](crate::Client::search_memes), the
+    /// `/get_memes` endpoint does not return any.
+    pub fn captions(&self) -> &[String] {
+        &self.captions
+    }
+
+    /// Downloads the blank template image through the given `client`.
+    ///
+    /// Accepts either a [`Client`](crate::Client) or an [`AccountClient`](crate::AccountClient),
+    /// so the download reuses that client's HTTP client, `User-Agent` and rate limit instead of
+    /// requiring a separate, unconfigured [`Client`](crate::Client).
+    pub async fn download(&self, client: &impl RequestConfig) -> Result<Image> {
+        download(client, self.url.clone()).await
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -68,14 +117,38 @@ enum Response<T> {
 }
 
 impl<T> Response<T> {
-    fn convert(self) -> Result<T> {
+    fn convert(self, endpoint: &'static str) -> Result<T> {
         match self {
             Response::SuccessResponse { data, .. } => Ok(data),
-            Response::FailureResponse { error_message, .. } => Err(Error::ApiError(error_message)),
+            Response::FailureResponse { error_message, .. } => Err(Error::ApiError {
+                endpoint,
+                rate_limited: is_rate_limit_message(&error_message),
+                message: error_message,
+            }),
         }
     }
 }
 
+/// Best-effort detection of imgflip's rate-limit/quota `error_message` wording
+fn is_rate_limit_message(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("rate limit")
+        || message.contains("too many requests")
+        || message.contains("request credits")
+}
+
+/// Returns `Err(Error::HttpStatus { .. })` for a non-2xx `response`, preserving `endpoint` and
+/// [`StatusCode`](reqwest::StatusCode) instead of discarding them like
+/// [`reqwest::Response::error_for_status`] does.
+fn check_status(response: reqwest::Response, endpoint: &'static str) -> Result<reqwest::Response> {
+    let status = response.status();
+    if status.is_success() {
+        Ok(response)
+    } else {
+        Err(Error::HttpStatus { endpoint, status })
+    }
+}
+
 /// Font for [`CaptionBoxesRequest`](crate::CaptionBoxesRequest)
 ///
 /// API defaults to `Impact`
@@ -205,6 +278,144 @@ impl CaptionBoxesRequestBuilder {
     }
 }
 
+/// Request data for a simple top/bottom caption of a meme template
+///
+/// Unlike [`CaptionBoxesRequest`](crate::CaptionBoxesRequest) this serializes as the `text0`/
+/// `text1` form fields the `/caption_image` endpoint accepts directly, instead of the
+/// `boxes[]` encoding.
+#[derive(Debug, Serialize)]
+pub struct TopBottomCaptionRequest {
+    template_id: String,
+    #[serde(rename = "text0")]
+    text_top: String,
+    #[serde(rename = "text1")]
+    text_bottom: String,
+    font: Option<CaptionFont>,
+    max_font_size: Option<u32>,
+}
+
+/// Builder for [`TopBottomCaptionRequest`](crate::TopBottomCaptionRequest)
+pub struct TopBottomCaptionRequestBuilder {
+    template_id: String,
+    text_top: String,
+    text_bottom: String,
+    font: Option<CaptionFont>,
+    max_font_size: Option<u32>,
+}
+
+impl TopBottomCaptionRequestBuilder {
+    pub fn new<S1: Into<String>, S2: Into<String>, S3: Into<String>>(
+        template_id: S1,
+        text_top: S2,
+        text_bottom: S3,
+    ) -> Self {
+        TopBottomCaptionRequestBuilder {
+            template_id: template_id.into(),
+            text_top: text_top.into(),
+            text_bottom: text_bottom.into(),
+            font: None,
+            max_font_size: None,
+        }
+    }
+
+    pub fn font(mut self, font: CaptionFont) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    pub fn max_font_size(mut self, max_font_size: u32) -> Self {
+        self.max_font_size = Some(max_font_size);
+        self
+    }
+
+    pub fn build(self) -> TopBottomCaptionRequest {
+        TopBottomCaptionRequest {
+            template_id: self.template_id,
+            text_top: self.text_top,
+            text_bottom: self.text_bottom,
+            font: self.font,
+            max_font_size: self.max_font_size,
+        }
+    }
+}
+
+/// Request data passed to [`AccountClient::caption_image`](crate::AccountClient::caption_image)
+///
+/// Either a [`TopBottomCaptionRequest`](crate::TopBottomCaptionRequest) for the common
+/// two-line meme, or a [`CaptionBoxesRequest`](crate::CaptionBoxesRequest) for full control
+/// over individual caption boxes.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum ImageCaptionRequest {
+    TopBottom(TopBottomCaptionRequest),
+    Boxes(CaptionBoxesRequest),
+}
+
+impl From<TopBottomCaptionRequest> for ImageCaptionRequest {
+    fn from(request: TopBottomCaptionRequest) -> Self {
+        ImageCaptionRequest::TopBottom(request)
+    }
+}
+
+impl From<CaptionBoxesRequest> for ImageCaptionRequest {
+    fn from(request: CaptionBoxesRequest) -> Self {
+        ImageCaptionRequest::Boxes(request)
+    }
+}
+
+/// Request data to search meme templates by keyword
+#[derive(Debug, Serialize)]
+pub struct SearchMemesRequest {
+    query: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_nsfw: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uploader: Option<String>,
+}
+
+/// Builder for [`SearchMemesRequest`](crate::SearchMemesRequest)
+pub struct SearchMemesRequestBuilder {
+    query: String,
+    include_nsfw: Option<bool>,
+    uploader: Option<String>,
+}
+
+impl SearchMemesRequestBuilder {
+    pub fn new<S: Into<String>>(query: S) -> Self {
+        SearchMemesRequestBuilder {
+            query: query.into(),
+            include_nsfw: None,
+            uploader: None,
+        }
+    }
+
+    /// Includes NSFW meme templates in the search results.
+    ///
+    /// This is a premium feature and is only honored when calling
+    /// [`AccountClient::search_memes_with`](crate::AccountClient::search_memes_with), which
+    /// authenticates the request with the account's `username`/`password`.
+    /// [`Client::search_memes_with`](crate::Client::search_memes_with) sends this request
+    /// unauthenticated, so `api.imgflip.com` will not return NSFW results.
+    pub fn include_nsfw(mut self, include_nsfw: bool) -> Self {
+        self.include_nsfw = Some(include_nsfw);
+        self
+    }
+
+    /// Scopes the search to meme templates uploaded by the given account.
+    pub fn uploader<S: Into<String>>(mut self, uploader: S) -> Self {
+        self.uploader = Some(uploader.into());
+        self
+    }
+
+    pub fn build(self) -> SearchMemesRequest {
+        SearchMemesRequest {
+            query: self.query,
+            include_nsfw: self.include_nsfw,
+            uploader: self.uploader,
+        }
+    }
+}
+
 /// A captioned meme template
 #[derive(Debug, Deserialize)]
 pub struct CaptionImageResponse {
@@ -222,6 +433,81 @@ impl CaptionImageResponse {
     pub fn page_url(&self) -> &Url {
         &self.page_url
     }
+
+    /// Downloads the generated captioned image through the given `client`.
+    ///
+    /// Accepts either a [`Client`](crate::Client) or an [`AccountClient`](crate::AccountClient)
+    /// — typically the same [`AccountClient`](crate::AccountClient) that produced this response
+    /// via [`caption_image`](crate::AccountClient::caption_image) — so the download reuses its
+    /// HTTP client, `User-Agent` and rate limit instead of requiring a separate, unconfigured
+    /// [`Client`](crate::Client).
+    pub async fn download(&self, client: &impl RequestConfig) -> Result<Image> {
+        download(client, self.url.clone()).await
+    }
+}
+
+/// Downloaded image bytes, e.g. from [`MemeTemplate::download`](crate::MemeTemplate::download)
+/// or [`CaptionImageResponse::download`](crate::CaptionImageResponse::download)
+#[derive(Debug, Clone)]
+pub struct Image {
+    bytes: bytes::Bytes,
+    content_type: Option<String>,
+}
+
+impl Image {
+    /// Returns the raw image bytes
+    pub fn bytes(&self) -> &bytes::Bytes {
+        &self.bytes
+    }
+
+    /// Returns the `Content-Type` header of the response, if any was sent
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+}
+
+/// Shared request configuration exposed by [`Client`](crate::Client) and
+/// [`AccountClient`](crate::AccountClient), so helpers like
+/// [`MemeTemplate::download`](crate::MemeTemplate::download) work with either one.
+///
+/// Sealed: only implemented by this crate's own client types.
+pub trait RequestConfig: private::Sealed {
+    #[doc(hidden)]
+    fn http_client(&self) -> &reqwest::Client;
+    #[doc(hidden)]
+    fn user_agent(&self) -> &str;
+    #[doc(hidden)]
+    fn rate_limiter(&self) -> Option<&TokenBucket>;
+}
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for super::Client {}
+    impl Sealed for super::AccountClient {}
+}
+
+async fn download(config: &impl RequestConfig, url: Url) -> Result<Image> {
+    if let Some(rate_limiter) = config.rate_limiter() {
+        rate_limiter.acquire().await;
+    }
+
+    let response = config
+        .http_client()
+        .get(url)
+        .header(USER_AGENT, config.user_agent())
+        .send()
+        .await?;
+    let response = check_status(response, "download")?;
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string());
+    let bytes = response.bytes().await?;
+    Ok(Image {
+        bytes,
+        content_type,
+    })
 }
 
 /// [`Error`](std:error:Error) implementation for all crate errors
@@ -235,14 +521,218 @@ pub enum Error {
     #[error("form querystring de/serialization error")]
     SerdeQs(#[from] serde_qs::Error),
 
-    /// API error message from `api.imgflip.com`
-    #[error("API error: {0}")]
-    ApiError(String),
+    /// Non-2xx HTTP status returned by the given `api.imgflip.com` endpoint
+    #[error("{endpoint} responded with HTTP status {status}")]
+    HttpStatus {
+        endpoint: &'static str,
+        status: reqwest::StatusCode,
+    },
+
+    /// `success: false` API error message from the given `api.imgflip.com` endpoint
+    #[error("{endpoint} API error: {message}")]
+    ApiError {
+        endpoint: &'static str,
+        message: String,
+        /// Best-effort guess, from the wording of `message`, that this failure was due to
+        /// imgflip's rate limiting or request quota rather than a domain error
+        rate_limited: bool,
+    },
 }
 
 /// [`Result`](std::result::Result) alias with crate's [`Error`](crate::Error)
 pub type Result<T> = std::result::Result<T, crate::Error>;
 
+/// Token-bucket rate limit configuration for outbound `api.imgflip.com` requests
+///
+/// `capacity` tokens refill at `per_second` tokens/second; once exhausted, requests wait for
+/// a token to become available instead of failing. Configure it via
+/// [`ClientBuilder::rate_limit`](crate::ClientBuilder::rate_limit), or disable it entirely
+/// with [`ClientBuilder::no_rate_limit`](crate::ClientBuilder::no_rate_limit).
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimit {
+    capacity: f64,
+    per_second: f64,
+}
+
+/// Smallest refill rate [`RateLimit::new`] allows, in tokens/second
+///
+/// Clamping a non-positive/non-finite `per_second` to [`f64::MIN_POSITIVE`] still lets
+/// [`TokenBucket::acquire`] compute a `wait` far outside the range [`Duration::from_secs_f64`]
+/// can represent (`(1.0 - tokens) / f64::MIN_POSITIVE` is itself a finite `f64`, around
+/// `4.5e307`, but panics when converted), so this needs to be large enough that the worst-case
+/// wait stays representable. `1e-6` caps it at roughly 11.6 days, comfortably inside range.
+const MIN_RATE_PER_SECOND: f64 = 1e-6;
+
+impl RateLimit {
+    /// Creates a new rate limit of `capacity` tokens refilling at `per_second` tokens/second
+    ///
+    /// `per_second` must refill the bucket in finite time representable as a
+    /// [`Duration`](std::time::Duration), so it is clamped to at least
+    /// [`MIN_RATE_PER_SECOND`]; a non-finite `per_second` (NaN or infinity) is replaced by
+    /// [`MIN_RATE_PER_SECOND`]. `capacity` is clamped to zero or more.
+    pub fn new(capacity: f64, per_second: f64) -> Self {
+        RateLimit {
+            capacity: capacity.max(0.0),
+            per_second: if per_second.is_finite() {
+                per_second.max(MIN_RATE_PER_SECOND)
+            } else {
+                MIN_RATE_PER_SECOND
+            },
+        }
+    }
+}
+
+impl Default for RateLimit {
+    /// A conservative default of one request per second, bursting up to 5
+    fn default() -> Self {
+        RateLimit::new(5.0, 1.0)
+    }
+}
+
+#[derive(Debug)]
+struct TokenBucketState {
+    tokens: f64,
+    last_refill: std::time::Instant,
+}
+
+/// Opaque token bucket backing a [`RateLimit`](crate::RateLimit), returned by
+/// [`RequestConfig::rate_limiter`](crate::RequestConfig::rate_limiter)
+///
+/// Has no public constructor or methods; it only exists at this visibility so that
+/// [`RequestConfig`](crate::RequestConfig) can be a public, sealed trait.
+#[derive(Debug)]
+pub struct TokenBucket {
+    capacity: f64,
+    rate: f64,
+    state: tokio::sync::Mutex<TokenBucketState>,
+}
+
+impl TokenBucket {
+    fn new(rate_limit: RateLimit) -> Self {
+        TokenBucket {
+            capacity: rate_limit.capacity,
+            rate: rate_limit.per_second,
+            state: tokio::sync::Mutex::new(TokenBucketState {
+                tokens: rate_limit.capacity,
+                last_refill: std::time::Instant::now(),
+            }),
+        }
+    }
+
+    async fn acquire(&self) {
+        let mut state = self.state.lock().await;
+
+        let now = std::time::Instant::now();
+        let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+        state.last_refill = now;
+        state.tokens = (state.tokens + elapsed * self.rate).min(self.capacity);
+
+        if state.tokens < 1.0 {
+            let wait = ((1.0 - state.tokens) / self.rate).max(0.0);
+            if wait.is_finite() {
+                tokio::time::sleep(std::time::Duration::from_secs_f64(wait)).await;
+            }
+            state.tokens = 0.0;
+            state.last_refill = std::time::Instant::now();
+        } else {
+            state.tokens -= 1.0;
+        }
+    }
+}
+
+/// Builder for [`Client`](crate::Client) and [`AccountClient`](crate::AccountClient)
+///
+/// Lets callers inject their own [`reqwest::Client`](reqwest::Client) (e.g. to configure
+/// timeouts or proxies), override the API base URL (useful to point at a mock server in
+/// tests), and set a custom `User-Agent` header.
+/// # Example
+/// ```no_run
+/// let client = imgflip::ClientBuilder::new()
+///     .user_agent("my-bot/1.0")
+///     .build();
+/// ```
+pub struct ClientBuilder {
+    client: Option<reqwest::Client>,
+    base_url: String,
+    user_agent: String,
+    rate_limit: Option<RateLimit>,
+}
+
+impl ClientBuilder {
+    /// Creates a new builder with default values
+    pub fn new() -> Self {
+        ClientBuilder {
+            client: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            rate_limit: Some(RateLimit::default()),
+        }
+    }
+
+    /// Uses the given [`reqwest::Client`](reqwest::Client) instead of a default one.
+    ///
+    /// This is useful to share connection pooling with the rest of an application, or to
+    /// configure timeouts and proxies.
+    pub fn http_client(mut self, client: reqwest::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Overrides the `api.imgflip.com` base URL, e.g. to point at a mock server in tests.
+    pub fn base_url<S: Into<String>>(mut self, base_url: S) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Overrides the `User-Agent` header sent with every request.
+    pub fn user_agent<S: Into<String>>(mut self, user_agent: S) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Overrides the token-bucket [`RateLimit`](crate::RateLimit) applied to every request.
+    ///
+    /// Defaults to [`RateLimit::default`](crate::RateLimit::default).
+    pub fn rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Disables rate limiting entirely.
+    pub fn no_rate_limit(mut self) -> Self {
+        self.rate_limit = None;
+        self
+    }
+
+    /// Builds a [`Client`](crate::Client) that does not require an account.
+    pub fn build(self) -> Client {
+        Client {
+            client: self.client.unwrap_or_default(),
+            base_url: self.base_url,
+            user_agent: self.user_agent,
+            rate_limiter: self.rate_limit.map(TokenBucket::new),
+        }
+    }
+
+    /// Builds an [`AccountClient`](crate::AccountClient) for the given account.
+    pub fn build_account<S: Into<String>>(self, username: S, password: S) -> AccountClient {
+        AccountClient {
+            client: self.client.unwrap_or_default(),
+            base_url: self.base_url,
+            user_agent: self.user_agent,
+            username: username.into(),
+            password: password.into(),
+            rate_limiter: self.rate_limit.map(TokenBucket::new),
+        }
+    }
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Client for `api.imgflip.com` that obtains blank meme templates
 ///
 /// You should resuse `Client` instances, since they do internal connection pooling.
@@ -258,36 +748,132 @@ pub type Result<T> = std::result::Result<T, crate::Error>;
 /// ```
 pub struct Client {
     client: reqwest::Client,
+    base_url: String,
+    user_agent: String,
+    rate_limiter: Option<TokenBucket>,
+}
+
+impl RequestConfig for Client {
+    fn http_client(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    fn user_agent(&self) -> &str {
+        &self.user_agent
+    }
+
+    fn rate_limiter(&self) -> Option<&TokenBucket> {
+        self.rate_limiter.as_ref()
+    }
 }
 
 impl Client {
     /// Creates a new instance with default values
+    ///
+    /// Use [`ClientBuilder`](crate::ClientBuilder) to customize the underlying HTTP client,
+    /// base URL, `User-Agent` header or rate limit.
     pub fn new() -> Self {
-        Client {
-            client: reqwest::Client::new(),
-        }
+        ClientBuilder::new().build()
     }
 
-    async fn client_memes(client: &reqwest::Client) -> Result<Vec<MemeTemplate>> {
+    async fn client_memes(
+        client: &reqwest::Client,
+        base_url: &str,
+        user_agent: &str,
+        rate_limiter: Option<&TokenBucket>,
+    ) -> Result<Vec<MemeTemplate>> {
         #[derive(Debug, Deserialize)]
         struct MemeTemplatesData {
             memes: Vec<MemeTemplate>,
         }
 
-        client
-            .get("https://api.imgflip.com/get_memes")
+        if let Some(rate_limiter) = rate_limiter {
+            rate_limiter.acquire().await;
+        }
+
+        let response = client
+            .get(format!("{}/get_memes", base_url))
+            .header(USER_AGENT, user_agent)
             .send()
-            .await?
-            .error_for_status()?
+            .await?;
+        check_status(response, "get_memes")?
             .json::<Response<MemeTemplatesData>>()
             .await?
-            .convert()
+            .convert("get_memes")
             .map(|r| r.memes)
     }
 
     /// Calls the `/get_memes` endpoint to return a list of popular meme templates
     pub async fn memes(&self) -> Result<Vec<MemeTemplate>> {
-        Self::client_memes(&self.client).await
+        Self::client_memes(
+            &self.client,
+            &self.base_url,
+            &self.user_agent,
+            self.rate_limiter.as_ref(),
+        )
+        .await
+    }
+
+    async fn client_search_memes(
+        client: &reqwest::Client,
+        base_url: &str,
+        user_agent: &str,
+        rate_limiter: Option<&TokenBucket>,
+        request: SearchMemesRequest,
+    ) -> Result<Vec<MemeTemplate>> {
+        #[derive(Debug, Deserialize)]
+        struct MemeTemplatesData {
+            memes: Vec<MemeTemplate>,
+        }
+
+        if let Some(rate_limiter) = rate_limiter {
+            rate_limiter.acquire().await;
+        }
+
+        let response = client
+            .get(format!("{}/search_memes", base_url))
+            .header(USER_AGENT, user_agent)
+            .query(&request)
+            .send()
+            .await?;
+        check_status(response, "search_memes")?
+            .json::<Response<MemeTemplatesData>>()
+            .await?
+            .convert("search_memes")
+            .map(|r| r.memes)
+    }
+
+    /// Calls the `/search_memes` endpoint to look up meme templates by keyword
+    ///
+    /// This returns richer catalog metadata than [`memes`](Client::memes), such as
+    /// [`MemeTemplate::captions`]. This is sent unauthenticated; use
+    /// [`AccountClient::search_memes_with`](crate::AccountClient::search_memes_with) with
+    /// [`include_nsfw`](SearchMemesRequestBuilder::include_nsfw) to unlock NSFW results.
+    pub async fn search_memes(&self, query: &str) -> Result<Vec<MemeTemplate>> {
+        Self::client_search_memes(
+            &self.client,
+            &self.base_url,
+            &self.user_agent,
+            self.rate_limiter.as_ref(),
+            SearchMemesRequestBuilder::new(query).build(),
+        )
+        .await
+    }
+
+    /// Calls the `/search_memes` endpoint with [`include_nsfw`](SearchMemesRequestBuilder::include_nsfw)
+    /// and [`uploader`](SearchMemesRequestBuilder::uploader) filtering
+    ///
+    /// This is sent unauthenticated, so `api.imgflip.com` will not honor `include_nsfw`; use
+    /// [`AccountClient::search_memes_with`](crate::AccountClient::search_memes_with) for that.
+    pub async fn search_memes_with(&self, request: SearchMemesRequest) -> Result<Vec<MemeTemplate>> {
+        Self::client_search_memes(
+            &self.client,
+            &self.base_url,
+            &self.user_agent,
+            self.rate_limiter.as_ref(),
+            request,
+        )
+        .await
     }
 }
 
@@ -298,22 +884,42 @@ pub struct AccountClient {
     username: String,
     password: String,
     client: reqwest::Client,
+    base_url: String,
+    user_agent: String,
+    rate_limiter: Option<TokenBucket>,
+}
+
+impl RequestConfig for AccountClient {
+    fn http_client(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    fn user_agent(&self) -> &str {
+        &self.user_agent
+    }
+
+    fn rate_limiter(&self) -> Option<&TokenBucket> {
+        self.rate_limiter.as_ref()
+    }
 }
 
 impl AccountClient {
     /// Creates a new instance for the given account
+    ///
+    /// Use [`ClientBuilder`](crate::ClientBuilder) to customize the underlying HTTP client,
+    /// base URL, `User-Agent` header or rate limit.
     pub fn new<S: Into<String>>(username: S, password: S) -> Self {
-        AccountClient {
-            client: reqwest::Client::new(),
-            username: username.into(),
-            password: password.into(),
-        }
+        ClientBuilder::new().build_account(username.into(), password.into())
     }
 
-    /// Calls the `/caption_image` endpoint to add caption boxes to a meme template
+    /// Calls the `/caption_image` endpoint to add a caption to a meme template
+    ///
+    /// Accepts either a [`TopBottomCaptionRequest`](crate::TopBottomCaptionRequest) for a
+    /// simple two-line meme or a [`CaptionBoxesRequest`](crate::CaptionBoxesRequest) for full
+    /// control over individual caption boxes.
     pub async fn caption_image(
         &self,
-        image_caption: CaptionBoxesRequest,
+        image_caption: impl Into<ImageCaptionRequest>,
     ) -> Result<CaptionImageResponse> {
         #[derive(Debug, Serialize)]
         struct RequestAuthWrapper<T> {
@@ -323,27 +929,161 @@ impl AccountClient {
             password: String,
         }
 
-        self.client
-            .post("https://api.imgflip.com/caption_image")
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+
+        let response = self
+            .client
+            .post(format!("{}/caption_image", self.base_url))
+            .header(USER_AGENT, &self.user_agent)
             .header(
                 CONTENT_TYPE,
                 HeaderValue::from_static("application/x-www-form-urlencoded"),
             )
             .body(serde_qs::to_string(&RequestAuthWrapper {
-                request: image_caption,
+                request: image_caption.into(),
                 username: self.username.clone(),
                 password: self.password.clone(),
             })?)
             .send()
-            .await?
-            .error_for_status()?
+            .await?;
+        check_status(response, "caption_image")?
             .json::<Response<CaptionImageResponse>>()
             .await?
-            .convert()
+            .convert("caption_image")
     }
 
     /// Calls the `/get_memes` endpoint to return a list of popular meme templates
     pub async fn memes(&self) -> Result<Vec<MemeTemplate>> {
-        Client::client_memes(&self.client).await
+        Client::client_memes(
+            &self.client,
+            &self.base_url,
+            &self.user_agent,
+            self.rate_limiter.as_ref(),
+        )
+        .await
+    }
+
+    async fn client_search_memes_authenticated(
+        client: &reqwest::Client,
+        base_url: &str,
+        user_agent: &str,
+        rate_limiter: Option<&TokenBucket>,
+        username: &str,
+        password: &str,
+        request: SearchMemesRequest,
+    ) -> Result<Vec<MemeTemplate>> {
+        #[derive(Debug, Serialize)]
+        struct RequestAuthWrapper<T> {
+            #[serde(flatten)]
+            request: T,
+            username: String,
+            password: String,
+        }
+        #[derive(Debug, Deserialize)]
+        struct MemeTemplatesData {
+            memes: Vec<MemeTemplate>,
+        }
+
+        if let Some(rate_limiter) = rate_limiter {
+            rate_limiter.acquire().await;
+        }
+
+        let response = client
+            .get(format!("{}/search_memes", base_url))
+            .header(USER_AGENT, user_agent)
+            .query(&RequestAuthWrapper {
+                request,
+                username: username.to_string(),
+                password: password.to_string(),
+            })
+            .send()
+            .await?;
+        check_status(response, "search_memes")?
+            .json::<Response<MemeTemplatesData>>()
+            .await?
+            .convert("search_memes")
+            .map(|r| r.memes)
+    }
+
+    /// Calls the `/search_memes` endpoint to look up meme templates by keyword, authenticated
+    /// with this account
+    pub async fn search_memes(&self, query: &str) -> Result<Vec<MemeTemplate>> {
+        Self::client_search_memes_authenticated(
+            &self.client,
+            &self.base_url,
+            &self.user_agent,
+            self.rate_limiter.as_ref(),
+            &self.username,
+            &self.password,
+            SearchMemesRequestBuilder::new(query).build(),
+        )
+        .await
+    }
+
+    /// Calls the `/search_memes` endpoint with [`include_nsfw`](SearchMemesRequestBuilder::include_nsfw)
+    /// and [`uploader`](SearchMemesRequestBuilder::uploader) filtering, authenticated with
+    /// this account so NSFW results can actually be returned
+    pub async fn search_memes_with(&self, request: SearchMemesRequest) -> Result<Vec<MemeTemplate>> {
+        Self::client_search_memes_authenticated(
+            &self.client,
+            &self.base_url,
+            &self.user_agent,
+            self.rate_limiter.as_ref(),
+            &self.username,
+            &self.password,
+            request,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn acquire_does_not_panic_exhausting_bucket_with_non_positive_rate() {
+        let bucket = TokenBucket::new(RateLimit::new(1.0, 0.0));
+        bucket.acquire().await;
+        bucket.acquire().await;
+    }
+
+    #[test]
+    fn deserializes_premium_search_memes_result() {
+        let json = r#"{
+            "id": "61579",
+            "name": "One Does Not Simply",
+            "url": "https://i.imgflip.com/1bij.jpg",
+            "width": 568,
+            "height": 335,
+            "box_count": "2",
+            "captions": ["one does not simply walk into mordor"],
+            "page_url": "https://imgflip.com/meme/61579"
+        }"#;
+
+        let meme: MemeTemplate = serde_json::from_str(json).unwrap();
+        assert_eq!(meme.box_count(), 2);
+        assert_eq!(
+            meme.captions(),
+            &["one does not simply walk into mordor".to_string()]
+        );
+    }
+
+    #[test]
+    fn deserializes_get_memes_result_without_premium_fields() {
+        let json = r#"{
+            "id": "61579",
+            "name": "One Does Not Simply",
+            "url": "https://i.imgflip.com/1bij.jpg",
+            "width": 568,
+            "height": 335,
+            "box_count": 2
+        }"#;
+
+        let meme: MemeTemplate = serde_json::from_str(json).unwrap();
+        assert_eq!(meme.box_count(), 2);
+        assert!(meme.captions().is_empty());
     }
 }